@@ -13,6 +13,7 @@ pub struct FillPath {
     pub rule: tiny_skia::FillRule,
     pub anti_alias: bool,
     pub path: Rc<tiny_skia::Path>,
+    pub shadow: Option<PathShadow>,
 }
 
 pub struct StrokePath {
@@ -20,11 +21,59 @@ pub struct StrokePath {
     pub stroke: tiny_skia::Stroke,
     pub anti_alias: bool,
     pub path: Rc<tiny_skia::Path>,
+    pub shadow: Option<PathShadow>,
+}
+
+/// A per-path approximation of an `feDropShadow`, rendered inline before the
+/// path itself instead of going through the full filter pipeline.
+#[derive(Clone, Debug)]
+pub struct PathShadow {
+    pub offset: (f32, f32),
+    pub blur: f32,
+    pub color: tiny_skia::Color,
+    /// The other paint's geometry, when a path has both a fill and a stroke.
+    /// `feDropShadow` shadows the element's full painted result, not one
+    /// sub-path, so whichever of `FillPath`/`StrokePath` carries the
+    /// `PathShadow` (see `shadow_target`) also carries this so its shadow
+    /// renderer can rasterize the union of both silhouettes into one mask
+    /// instead of just its own.
+    pub companion: Option<ShadowCompanion>,
+}
+
+/// The sibling paint's geometry to fold into a [`PathShadow`]'s mask
+/// alongside its carrier's own geometry.
+#[derive(Clone, Debug)]
+pub enum ShadowCompanion {
+    Fill {
+        path: Rc<tiny_skia::Path>,
+        rule: tiny_skia::FillRule,
+    },
+    Stroke {
+        path: Rc<tiny_skia::Path>,
+        stroke: tiny_skia::Stroke,
+    },
+}
+
+/// Rasterization limits carried on [`Context`], used to keep paths whose
+/// device-space bounds exceed tiny-skia's internal supersampling limit
+/// (`MAX_DIM`, ~8191px) from silently rendering as blank.
+#[derive(Clone, Copy, Debug)]
+pub struct RenderLimits {
+    pub max_raster_dimension: u32,
+}
+
+impl Default for RenderLimits {
+    fn default() -> Self {
+        Self {
+            max_raster_dimension: 8191,
+        }
+    }
 }
 
 pub fn convert(
     upath: &usvg::Path,
     text_bbox: Option<tiny_skia::NonZeroRect>,
+    filters: &[usvg::filter::Filter],
     children: &mut Vec<Node>,
 ) -> Option<usvg::BBox> {
     let anti_alias = upath.rendering_mode.use_shape_antialiasing();
@@ -39,19 +88,46 @@ pub fn convert(
         bounding_box = text_bbox.to_rect();
     }
 
-    let fill_path = upath
-        .fill
-        .as_ref()
-        .and_then(|ufill| convert_fill_path(ufill, upath.data.clone(), bounding_box, anti_alias));
+    // The fast path only fires when `filters` is exactly one lone
+    // `feDropShadow`; anything else (extra primitives, more than one
+    // filter) still goes through the full filter pipeline elsewhere.
+    let shadow = single_drop_shadow(filters);
+
+    let mut fill_path = upath.fill.as_ref().and_then(|ufill| {
+        convert_fill_path(ufill, upath.data.clone(), bounding_box, anti_alias, None)
+    });
 
-    let stroke_path = upath.stroke.as_ref().and_then(|ustroke| {
-        convert_stroke_path(ustroke, upath.data.clone(), bounding_box, anti_alias)
+    let mut stroke_path = upath.stroke.as_ref().and_then(|ustroke| {
+        convert_stroke_path(ustroke, upath.data.clone(), bounding_box, anti_alias, None)
     });
 
     if fill_path.is_none() && stroke_path.is_none() {
         return None;
     }
 
+    // `Node::FillPath` and `Node::StrokePath` each render their own shadow
+    // independently, so a shadow attached to both would be rasterized and
+    // composited twice for a path with both a fill and a stroke. Attach it
+    // only to whichever of the two paints first (per `paint_order`) -- but
+    // `feDropShadow` shadows the element's full painted result, not one
+    // sub-path, so the carrier also gets a `ShadowCompanion` pointing at the
+    // other paint's geometry, and its shadow renderer folds that into the
+    // same mask (see `render_fill_path_shadow`/`render_stroke_path_shadow`).
+    if let Some(shadow) = shadow {
+        let fill_geometry = fill_path.as_ref().map(|p| (p.path.clone(), p.rule));
+        let stroke_geometry = stroke_path
+            .as_ref()
+            .map(|p| (p.path.clone(), p.stroke.clone()));
+        let (fill_shadow, stroke_shadow) =
+            shadow_for_paths(shadow, upath.paint_order, fill_geometry, stroke_geometry);
+        if let Some(s) = fill_shadow {
+            fill_path.as_mut().unwrap().shadow = Some(s);
+        }
+        if let Some(s) = stroke_shadow {
+            stroke_path.as_mut().unwrap().shadow = Some(s);
+        }
+    }
+
     let mut layer_bbox = usvg::BBox::from(bounding_box);
 
     if stroke_path.is_some() {
@@ -60,6 +136,31 @@ pub fn convert(
         }
     }
 
+    // A drop shadow can paint outside the path/stroke bbox, so widen the
+    // layer bbox accordingly and let the shadow render unclipped. The mask
+    // itself rasterizes the union of fill and stroke (see above), so pad
+    // from the union of their bounds too, not just whichever one happens to
+    // carry the `PathShadow` -- when there's a stroke, `stroke_bounding_box`
+    // (already stroke-width aware) covers that union, so a thick stroke's
+    // shadow isn't under-expanded and later cropped by an outer clip.
+    let has_shadow = fill_path.as_ref().is_some_and(|p| p.shadow.is_some())
+        || stroke_path.as_ref().is_some_and(|p| p.shadow.is_some());
+    if has_shadow {
+        let shadow_source_bounds = if stroke_path.is_some() {
+            upath.stroke_bounding_box.unwrap_or(bounding_box)
+        } else {
+            bounding_box
+        };
+        let shadow = fill_path
+            .as_ref()
+            .and_then(|p| p.shadow.clone())
+            .or_else(|| stroke_path.as_ref().and_then(|p| p.shadow.clone()))
+            .expect("has_shadow implies one of fill_path/stroke_path carries a shadow");
+        if let Some(shadow_bbox) = shadow_bounds(shadow_source_bounds, &shadow) {
+            layer_bbox = layer_bbox.expand(shadow_bbox);
+        }
+    }
+
     // Do not add hidden paths, but preserve the bbox.
     // visibility=hidden still affects the bbox calculation.
     if upath.visibility != usvg::Visibility::Visible {
@@ -92,6 +193,7 @@ fn convert_fill_path(
     path: Rc<tiny_skia::Path>,
     object_bbox: tiny_skia::Rect,
     anti_alias: bool,
+    shadow: Option<PathShadow>,
 ) -> Option<FillPath> {
     // Horizontal and vertical lines cannot be filled. Skip.
     if path.bounds().width() == 0.0 || path.bounds().height() == 0.0 {
@@ -111,6 +213,7 @@ fn convert_fill_path(
         rule,
         anti_alias,
         path,
+        shadow,
     };
 
     Some(path)
@@ -121,6 +224,7 @@ fn convert_stroke_path(
     path: Rc<tiny_skia::Path>,
     object_bbox: tiny_skia::Rect,
     anti_alias: bool,
+    shadow: Option<PathShadow>,
 ) -> Option<StrokePath> {
     // Zero-sized stroke path is not an error, because linecap round or square
     // would produce the shape either way.
@@ -137,11 +241,471 @@ fn convert_stroke_path(
         stroke: ustroke.to_tiny_skia(),
         anti_alias,
         path,
+        shadow,
     };
 
     Some(path)
 }
 
+/// Detects the fast path for the common `feDropShadow` case: `filters` is
+/// exactly one filter consisting of exactly one `DropShadow` primitive, with
+/// nothing else composited on top. Anything more elaborate (extra
+/// primitives, more than one filter, a `DropShadow` mixed with other
+/// effects) returns `None` so the caller keeps going through the full
+/// filter pipeline, which can represent any filter graph this can't.
+fn single_drop_shadow(filters: &[usvg::filter::Filter]) -> Option<PathShadow> {
+    let [filter] = filters else { return None };
+    let [primitive] = filter.primitives.as_slice() else {
+        return None;
+    };
+
+    let usvg::filter::Kind::DropShadow(ref fe) = primitive.kind else {
+        return None;
+    };
+
+    Some(PathShadow {
+        offset: (fe.dx, fe.dy),
+        blur: (fe.std_dev_x.get() + fe.std_dev_y.get()) / 2.0,
+        color: tiny_skia::Color::from_rgba8(
+            fe.color.red,
+            fe.color.green,
+            fe.color.blue,
+            (fe.opacity.get() * 255.0).round() as u8,
+        ),
+        // Filled in by `convert()` once it knows whether the path also has
+        // the other paint, since that determines the companion geometry.
+        companion: None,
+    })
+}
+
+/// Which of a path's fill/stroke draws should carry its [`PathShadow`], so
+/// that a path with both a fill and a stroke renders the shadow exactly once
+/// (whichever of the two paints first) instead of once per draw call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ShadowTarget {
+    Fill,
+    Stroke,
+    None,
+}
+
+fn shadow_target(paint_order: usvg::PaintOrder, has_fill: bool, has_stroke: bool) -> ShadowTarget {
+    let fill_paints_first = paint_order == usvg::PaintOrder::FillAndStroke;
+    if fill_paints_first {
+        if has_fill {
+            ShadowTarget::Fill
+        } else if has_stroke {
+            ShadowTarget::Stroke
+        } else {
+            ShadowTarget::None
+        }
+    } else if has_stroke {
+        ShadowTarget::Stroke
+    } else if has_fill {
+        ShadowTarget::Fill
+    } else {
+        ShadowTarget::None
+    }
+}
+
+/// Builds the `PathShadow`(s) that should be attached to a path's
+/// `fill_path`/`stroke_path`, given each one's own geometry (or `None` if
+/// that paint isn't present). At most one of the returned `(fill, stroke)`
+/// pair is ever `Some` -- see [`shadow_target`] -- and when the element has
+/// both a fill and a stroke, the carrier's shadow also gets a
+/// [`ShadowCompanion`] pointing at the other paint's geometry, so its
+/// renderer can shadow the union of both instead of just its own.
+fn shadow_for_paths(
+    shadow: PathShadow,
+    paint_order: usvg::PaintOrder,
+    fill: Option<(Rc<tiny_skia::Path>, tiny_skia::FillRule)>,
+    stroke: Option<(Rc<tiny_skia::Path>, tiny_skia::Stroke)>,
+) -> (Option<PathShadow>, Option<PathShadow>) {
+    match shadow_target(paint_order, fill.is_some(), stroke.is_some()) {
+        ShadowTarget::Fill => {
+            let companion = stroke.map(|(path, stroke)| ShadowCompanion::Stroke { path, stroke });
+            (Some(PathShadow { companion, ..shadow }), None)
+        }
+        ShadowTarget::Stroke => {
+            let companion = fill.map(|(path, rule)| ShadowCompanion::Fill { path, rule });
+            (None, Some(PathShadow { companion, ..shadow }))
+        }
+        ShadowTarget::None => (None, None),
+    }
+}
+
+/// Converts a stroke into an equivalent fill path tracing its outline.
+///
+/// This mirrors what Skia's `PathBuilderSkia`/`SkPathUtils::FillPathWithPaint`
+/// do: when `stroke` carries a dash array, the path is first split into its
+/// dashes with tiny-skia's `Path::dash` (its `.stroke()` ignores the dash),
+/// then tiny-skia's pen outliner walks the result using the stroke width,
+/// line caps, line joins and miter limit, producing a closed, NonZero-wound
+/// fill contour (see [`stroke_outline`]). Round and square caps on
+/// zero-length subpaths are handled by the outliner itself, which still
+/// emits the cap dot. Returns `None` for a zero-width stroke or if
+/// tiny-skia fails to dash or outline the path.
+///
+/// This lets a stroke be used anywhere a fill path is required, e.g. when
+/// feeding it into clip-path rasterization, hit-testing the painted region,
+/// or exporting flattened outline geometry.
+pub fn outline_stroke(path: &StrokePath) -> Option<FillPath> {
+    if path.stroke.width <= 0.0 {
+        return None;
+    }
+
+    Some(FillPath {
+        paint: path.paint.clone(),
+        rule: tiny_skia::FillRule::Winding,
+        anti_alias: path.anti_alias,
+        path: Rc::new(try_stroke_outline(&path.path, &path.stroke)?),
+        shadow: path.shadow.clone(),
+    })
+}
+
+/// Returns the stroke's actual painted outline (the same pen-outliner
+/// result `outline_stroke` produces, dash array included), falling back to
+/// a clone of `path` itself if tiny-skia can't build one (e.g. a zero-width
+/// stroke). Used wherever a stroke's real device-space extent is needed,
+/// since it can reach well past the bare centerline's own bounds.
+fn stroke_outline(path: &tiny_skia::Path, stroke: &tiny_skia::Stroke) -> tiny_skia::Path {
+    try_stroke_outline(path, stroke).unwrap_or_else(|| path.clone())
+}
+
+/// Applies `stroke`'s dash array, if any, then outlines the result; `None`
+/// if tiny-skia fails at either step.
+fn try_stroke_outline(
+    path: &tiny_skia::Path,
+    stroke: &tiny_skia::Stroke,
+) -> Option<tiny_skia::Path> {
+    match stroke.dash {
+        Some(ref dash) => path.dash(dash, 1.0)?.stroke(stroke, 1.0),
+        None => path.stroke(stroke, 1.0),
+    }
+}
+
+/// Approximates a uniform scale factor for `transform`, used to convert
+/// `stdDeviation` (a scalar, which has no direction to rotate) from user
+/// space into device space. Non-uniform scale or skew is approximated by
+/// averaging the transformed lengths of the two basis vectors -- the same
+/// kind of approximation [`single_drop_shadow`] already makes when it
+/// averages `stdDeviation`'s x and y into one blur value.
+///
+/// This is deliberately *not* used for the shadow offset: unlike blur, an
+/// offset is a vector with a direction, which a scalar can't represent. See
+/// [`transform_vector`].
+fn transform_scale(transform: tiny_skia::Transform) -> f32 {
+    let x_scale = (transform.sx * transform.sx + transform.ky * transform.ky).sqrt();
+    let y_scale = (transform.kx * transform.kx + transform.sy * transform.sy).sqrt();
+    (x_scale + y_scale) / 2.0
+}
+
+/// Maps a user-space vector (as opposed to a point) through `transform`'s
+/// linear part, ignoring its translation -- an offset has no origin to
+/// translate from, only a direction and length to rotate/scale.
+fn transform_vector(transform: tiny_skia::Transform, vector: (f32, f32)) -> (f32, f32) {
+    let (x, y) = vector;
+    (
+        transform.sx * x + transform.kx * y,
+        transform.ky * x + transform.sy * y,
+    )
+}
+
+/// Converts `shadow`'s blur and offset from user space into device space by
+/// `transform`, for combining with geometry that has already been mapped
+/// through `transform`. The offset is rotated/scaled as a vector through
+/// `transform`'s linear part, so a rotated or skewed CTM (e.g. a `<g
+/// transform="rotate(45)">` wrapping a shadowed shape) offsets the shadow in
+/// the right direction instead of keeping it axis-aligned; the blur radius,
+/// having no direction, uses the averaged scalar approximation instead.
+fn device_space_shadow(shadow: &PathShadow, transform: tiny_skia::Transform) -> PathShadow {
+    PathShadow {
+        offset: transform_vector(transform, shadow.offset),
+        blur: shadow.blur * transform_scale(transform),
+        color: shadow.color,
+        companion: shadow.companion.clone(),
+    }
+}
+
+/// Computes how far a [`PathShadow`] can paint outside `bounds` (the bounds
+/// of the shadowed geometry), in the same units, so callers can widen a layer
+/// bbox to avoid clipping the shadow.
+fn shadow_bounds(bounds: tiny_skia::Rect, shadow: &PathShadow) -> Option<tiny_skia::Rect> {
+    let pad = shadow.blur.max(0.0) * 3.0;
+    tiny_skia::Rect::from_ltrb(
+        bounds.left() + shadow.offset.0.min(0.0) - pad,
+        bounds.top() + shadow.offset.1.min(0.0) - pad,
+        bounds.right() + shadow.offset.0.max(0.0) + pad,
+        bounds.bottom() + shadow.offset.1.max(0.0) + pad,
+    )
+}
+
+/// Combines two device-space bounding rects into the smallest rect
+/// containing both, for sizing a shadow mask that must cover more than one
+/// piece of geometry (e.g. a path's fill and its stroke outline).
+fn union_rect(a: tiny_skia::Rect, b: tiny_skia::Rect) -> tiny_skia::Rect {
+    tiny_skia::Rect::from_ltrb(
+        a.left().min(b.left()),
+        a.top().min(b.top()),
+        a.right().max(b.right()),
+        a.bottom().max(b.bottom()),
+    )
+    .unwrap_or(a)
+}
+
+/// Computes the blurred, offset device-space bounds for a shadow mask,
+/// clamped to the target pixmap, as `(x0, y0, width, height)`.
+///
+/// The result is also clamped to `max_dim` on each side, same as
+/// [`for_each_tile`]'s tiling does for the path itself: a shadow mask is
+/// just another `tiny_skia::Pixmap::new` allocation, and an attacker-
+/// controlled canvas size plus a drop shadow can exceed tiny-skia's
+/// rasterization limit just as easily as the path fill/stroke can. Unlike
+/// the path itself, the shadow mask isn't tiled -- it's clamped and a
+/// warning is logged, so a huge shadow degrades (cropped) instead of
+/// panicking or silently failing to allocate.
+///
+/// `bounds` is already in device space and already covers whatever geometry
+/// the mask needs to hold (a path's own geometry, or the union of its
+/// geometry and a `ShadowCompanion`'s -- see the callers).
+fn shadow_mask_bounds(
+    bounds: tiny_skia::Rect,
+    shadow: &PathShadow,
+    pixmap: &tiny_skia::PixmapMut,
+    max_dim: u32,
+) -> Option<(f32, f32, u32, u32)> {
+    let pad = shadow.blur.max(0.0) * 3.0;
+
+    let x0 = (bounds.left() + shadow.offset.0.min(0.0) - pad)
+        .floor()
+        .max(0.0);
+    let y0 = (bounds.top() + shadow.offset.1.min(0.0) - pad)
+        .floor()
+        .max(0.0);
+    let x1 = (bounds.right() + shadow.offset.0.max(0.0) + pad)
+        .ceil()
+        .min(pixmap.width() as f32);
+    let y1 = (bounds.bottom() + shadow.offset.1.max(0.0) + pad)
+        .ceil()
+        .min(pixmap.height() as f32);
+
+    let width = (x1 - x0).max(0.0) as u32;
+    let height = (y1 - y0).max(0.0) as u32;
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let clamped_width = width.min(max_dim);
+    let clamped_height = height.min(max_dim);
+    if clamped_width != width || clamped_height != height {
+        log::warn!(
+            "a drop-shadow mask exceeds tiny-skia's rasterization limit; clamping instead of tiling"
+        );
+    }
+
+    Some((x0, y0, clamped_width, clamped_height))
+}
+
+/// Blurs a rasterized shadow mask in place with three box-blur passes (a
+/// standard approximation of a Gaussian blur of the requested standard
+/// deviation), tints it with the shadow color, and composites it into
+/// `pixmap` at `(x0, y0)`.
+fn blur_and_composite_shadow(
+    mask: &mut tiny_skia::Pixmap,
+    shadow: &PathShadow,
+    x0: f32,
+    y0: f32,
+    blend_mode: tiny_skia::BlendMode,
+    pixmap: &mut tiny_skia::PixmapMut,
+) {
+    let box_radius = clamped_box_radius(shadow.blur, mask.width().max(mask.height()));
+    if box_radius > 0 {
+        for _ in 0..3 {
+            box_blur(mask, box_radius, true);
+            box_blur(mask, box_radius, false);
+        }
+    }
+
+    tint_mask(mask, shadow.color);
+
+    pixmap.draw_pixmap(
+        (x0 + shadow.offset.0).round() as i32,
+        (y0 + shadow.offset.1).round() as i32,
+        mask.as_ref(),
+        &tiny_skia::PixmapPaint {
+            blend_mode,
+            ..Default::default()
+        },
+        tiny_skia::Transform::identity(),
+        None,
+    );
+}
+
+/// Converts a blur standard deviation into a box-blur radius, clamped to
+/// half of `mask_dim` (the mask's longest side). `shadow_blur` comes
+/// straight from `feDropShadow`'s `stdDeviation`, which is attacker-
+/// controlled SVG input; without this clamp a huge value would make
+/// `box_blur`'s per-row/column cost (proportional to the radius, not just
+/// the mask size) unbounded, and could overflow `2 * radius + 1`.
+fn clamped_box_radius(shadow_blur: f32, mask_dim: u32) -> i32 {
+    let radius = (shadow_blur.max(0.0) * 3.0f32.sqrt() / 2.0).round() as i32;
+    radius.clamp(0, mask_dim as i32 / 2)
+}
+
+/// A single box-blur pass over a mask's alpha channel using a running-sum
+/// sliding window, O(n) per row/column. Three passes approximate a Gaussian.
+fn box_blur(pixmap: &mut tiny_skia::Pixmap, radius: i32, horizontal: bool) {
+    let width = pixmap.width() as i32;
+    let height = pixmap.height() as i32;
+    let window = 2 * radius + 1;
+    let (outer, inner) = if horizontal {
+        (height, width)
+    } else {
+        (width, height)
+    };
+
+    let data = pixmap.data_mut();
+    let sample_index = |x: i32, y: i32| (y as usize * width as usize + x as usize) * 4 + 3;
+
+    let mut line = vec![0u8; inner as usize];
+    for o in 0..outer {
+        for i in 0..inner {
+            let (x, y) = if horizontal { (i, o) } else { (o, i) };
+            line[i as usize] = data[sample_index(x, y)];
+        }
+
+        let sample = |i: i32| -> i32 {
+            if i < 0 || i as usize >= line.len() {
+                0
+            } else {
+                line[i as usize] as i32
+            }
+        };
+
+        let mut sum: i32 = (-radius..=radius).map(sample).sum();
+        for i in 0..inner {
+            let (x, y) = if horizontal { (i, o) } else { (o, i) };
+            data[sample_index(x, y)] = (sum / window).clamp(0, 255) as u8;
+            sum += sample(i + radius + 1) - sample(i - radius);
+        }
+    }
+}
+
+/// Tints an alpha-only coverage mask (produced by filling/stroking with
+/// opaque black) with `color`, scaling the color's premultiplied channels
+/// by the mask's per-pixel coverage.
+fn tint_mask(mask: &mut tiny_skia::Pixmap, color: tiny_skia::Color) {
+    let premultiplied = color.premultiply();
+    let r = (premultiplied.red() * 255.0).round() as u32;
+    let g = (premultiplied.green() * 255.0).round() as u32;
+    let b = (premultiplied.blue() * 255.0).round() as u32;
+    let a = (premultiplied.alpha() * 255.0).round() as u32;
+
+    for pixel in mask.data_mut().chunks_exact_mut(4) {
+        let coverage = pixel[3] as u32;
+        pixel[0] = (r * coverage / 255) as u8;
+        pixel[1] = (g * coverage / 255) as u8;
+        pixel[2] = (b * coverage / 255) as u8;
+        pixel[3] = (a * coverage / 255) as u8;
+    }
+}
+
+fn render_fill_path_shadow(
+    path: &FillPath,
+    shadow: &PathShadow,
+    blend_mode: tiny_skia::BlendMode,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    pixmap: &mut tiny_skia::PixmapMut,
+) -> Option<()> {
+    // `shadow.blur`/`shadow.offset` are raw `feDropShadow` values in user
+    // space, but from here on they're combined with `path`'s bounds only
+    // after it has been mapped into device space by `transform` -- scale
+    // them into device space too, or a zoomed-in canvas would paint a
+    // shadow that's too tight (or too loose when zoomed out).
+    let shadow = &device_space_shadow(shadow, transform);
+
+    // When the element also has a stroke, `feDropShadow` shadows the full
+    // painted result, not just the fill -- fold the stroke's outline into
+    // the same mask instead of rasterizing the fill alone.
+    let stroke_companion = match &shadow.companion {
+        Some(ShadowCompanion::Stroke { path, stroke }) => {
+            Some((path.clone(), stroke_outline(path, stroke), stroke.clone()))
+        }
+        _ => None,
+    };
+
+    let mut bounds = path.path.clone().transform(transform)?.bounds();
+    if let Some((_, ref outline, _)) = stroke_companion {
+        bounds = union_rect(bounds, outline.clone().transform(transform)?.bounds());
+    }
+
+    let (x0, y0, width, height) =
+        shadow_mask_bounds(bounds, shadow, pixmap, ctx.limits.max_raster_dimension)?;
+    let mut mask = tiny_skia::Pixmap::new(width, height)?;
+
+    let mut mask_paint = tiny_skia::Paint::default();
+    mask_paint.set_color(tiny_skia::Color::BLACK);
+    mask_paint.anti_alias = true;
+
+    let local_transform = transform.post_translate(-x0, -y0);
+    mask.fill_path(&path.path, &mask_paint, path.rule, local_transform, None);
+    if let Some((stroke_path, _, stroke)) = stroke_companion {
+        mask.stroke_path(&stroke_path, &mask_paint, &stroke, local_transform, None);
+    }
+
+    blur_and_composite_shadow(&mut mask, shadow, x0, y0, blend_mode, pixmap);
+
+    Some(())
+}
+
+fn render_stroke_path_shadow(
+    path: &StrokePath,
+    shadow: &PathShadow,
+    blend_mode: tiny_skia::BlendMode,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    pixmap: &mut tiny_skia::PixmapMut,
+) -> Option<()> {
+    // See `render_fill_path_shadow`: `shadow`'s blur/offset are user-space
+    // values and must be scaled into device space before use below.
+    let shadow = &device_space_shadow(shadow, transform);
+
+    // When the element also has a fill, `feDropShadow` shadows the full
+    // painted result, not just the stroke -- fold the fill's geometry into
+    // the same mask instead of rasterizing the stroke alone.
+    let fill_companion = match &shadow.companion {
+        Some(ShadowCompanion::Fill { path, rule }) => Some((path.clone(), *rule)),
+        _ => None,
+    };
+
+    // Size the mask from the stroke's actual painted outline, not the bare
+    // centerline: a thick stroke extends well past `path.path`'s own bounds,
+    // and sizing from the centerline alone clips the stroke at the mask edge.
+    let outline = stroke_outline(&path.path, &path.stroke);
+    let mut bounds = outline.clone().transform(transform)?.bounds();
+    if let Some((ref fill_path, _)) = fill_companion {
+        bounds = union_rect(bounds, fill_path.clone().transform(transform)?.bounds());
+    }
+
+    let (x0, y0, width, height) =
+        shadow_mask_bounds(bounds, shadow, pixmap, ctx.limits.max_raster_dimension)?;
+    let mut mask = tiny_skia::Pixmap::new(width, height)?;
+
+    let mut mask_paint = tiny_skia::Paint::default();
+    mask_paint.set_color(tiny_skia::Color::BLACK);
+    mask_paint.anti_alias = true;
+
+    let local_transform = transform.post_translate(-x0, -y0);
+    mask.stroke_path(&path.path, &mask_paint, &path.stroke, local_transform, None);
+    if let Some((fill_path, rule)) = fill_companion {
+        mask.fill_path(&fill_path, &mask_paint, rule, local_transform, None);
+    }
+
+    blur_and_composite_shadow(&mut mask, shadow, x0, y0, blend_mode, pixmap);
+
+    Some(())
+}
+
 pub fn render_fill_path(
     path: &FillPath,
     blend_mode: tiny_skia::BlendMode,
@@ -149,6 +713,12 @@ pub fn render_fill_path(
     transform: tiny_skia::Transform,
     pixmap: &mut tiny_skia::PixmapMut,
 ) -> Option<()> {
+    if let Some(ref shadow) = path.shadow {
+        if shadow.color.alpha() > 0.0 {
+            render_fill_path_shadow(path, shadow, blend_mode, ctx, transform, pixmap);
+        }
+    }
+
     let pattern_pixmap;
     let mut paint = tiny_skia::Paint::default();
     match path.paint {
@@ -172,12 +742,179 @@ pub fn render_fill_path(
 
     paint.anti_alias = path.anti_alias;
     paint.blend_mode = blend_mode;
+    // Switch tiny-skia to its precise f32 pipeline when the caller needs
+    // deterministic, maximum-precision output (e.g. reference rendering in
+    // tests or subtle gradient edges), at the cost of rendering speed.
+    paint.force_hq_pipeline = ctx.force_hq;
 
-    pixmap.fill_path(&path.path, &paint, path.rule, transform, None);
+    match oversized_bounds(&path.path, transform, ctx.limits.max_raster_dimension) {
+        Some(bounds) => render_fill_path_tiled(
+            path,
+            &paint,
+            transform,
+            pixmap,
+            bounds,
+            ctx.limits.max_raster_dimension,
+        ),
+        None => {
+            pixmap.fill_path(&path.path, &paint, path.rule, transform, None);
+            Some(())
+        }
+    }
+}
+
+/// tiny-skia silently refuses to rasterize geometry whose supersampled
+/// device-space dimension exceeds [`RenderLimits::max_raster_dimension`]
+/// (`MAX_DIM`, ~8191px), producing blank output with no error. Returns the
+/// path's device-space bounds when either dimension exceeds `max_dim`, so
+/// the caller can tile the draw instead of dropping it.
+fn oversized_bounds(
+    path: &tiny_skia::Path,
+    transform: tiny_skia::Transform,
+    max_dim: u32,
+) -> Option<tiny_skia::Rect> {
+    let bounds = path.clone().transform(transform)?.bounds();
+    if bounds.width() > max_dim as f32 || bounds.height() > max_dim as f32 {
+        Some(bounds)
+    } else {
+        None
+    }
+}
+
+/// Splits `bounds` (clamped to the target pixmap) into tiles no larger than
+/// `max_dim` on a side and invokes `render_tile(x, y, width, height)` for
+/// each, in raster order. Bails out with a warning, instead of silently
+/// skipping, if `bounds` doesn't intersect the pixmap at all.
+fn for_each_tile(
+    pixmap_width: u32,
+    pixmap_height: u32,
+    bounds: tiny_skia::Rect,
+    max_dim: u32,
+    mut render_tile: impl FnMut(u32, u32, u32, u32) -> Option<()>,
+) -> Option<()> {
+    let x0 = (bounds.left().floor().max(0.0) as u32).min(pixmap_width);
+    let y0 = (bounds.top().floor().max(0.0) as u32).min(pixmap_height);
+    let x1 = (bounds.right().ceil().max(0.0) as u32).min(pixmap_width);
+    let y1 = (bounds.bottom().ceil().max(0.0) as u32).min(pixmap_height);
+
+    if x1 <= x0 || y1 <= y0 {
+        log::warn!("a path exceeds tiny-skia's rasterization limit and is off-canvas; skipping");
+        return None;
+    }
+
+    let mut y = y0;
+    while y < y1 {
+        let th = (y1 - y).min(max_dim);
+        let mut x = x0;
+        while x < x1 {
+            let tw = (x1 - x).min(max_dim);
+            render_tile(x, y, tw, th)?;
+            x += tw;
+        }
+        y += th;
+    }
 
     Some(())
 }
 
+/// Composites a rendered tile back into `pixmap` at `(x, y)` using
+/// `blend_mode`. The tile itself is always painted with `SourceOver` onto
+/// its own transparent pixmap; the real blend mode only applies once here,
+/// against the already-painted target.
+fn composite_tile(
+    pixmap: &mut tiny_skia::PixmapMut,
+    tile: &tiny_skia::Pixmap,
+    x: u32,
+    y: u32,
+    blend_mode: tiny_skia::BlendMode,
+) {
+    pixmap.draw_pixmap(
+        x as i32,
+        y as i32,
+        tile.as_ref(),
+        &tiny_skia::PixmapPaint {
+            blend_mode,
+            ..Default::default()
+        },
+        tiny_skia::Transform::identity(),
+        None,
+    );
+}
+
+/// Renders a fill that is too large for tiny-skia to rasterize directly by
+/// splitting the target pixmap into `max_dim`-sized tiles, rendering each
+/// tile into its own small pixmap (with the transform translated so the
+/// tile's origin lands at its own (0, 0), and with `SourceOver` blending so
+/// the tile's transparent background isn't blended against), and
+/// compositing the results back with the path's real blend mode.
+fn render_fill_path_tiled(
+    path: &FillPath,
+    paint: &tiny_skia::Paint,
+    transform: tiny_skia::Transform,
+    pixmap: &mut tiny_skia::PixmapMut,
+    bounds: tiny_skia::Rect,
+    max_dim: u32,
+) -> Option<()> {
+    let blend_mode = paint.blend_mode;
+    let mut tile_paint = paint.clone();
+    tile_paint.blend_mode = tiny_skia::BlendMode::SourceOver;
+
+    for_each_tile(
+        pixmap.width(),
+        pixmap.height(),
+        bounds,
+        max_dim,
+        |x, y, tw, th| {
+            let mut tile_pixmap = tiny_skia::Pixmap::new(tw, th)?;
+            let tile_transform = transform.post_translate(-(x as f32), -(y as f32));
+            tile_pixmap.as_mut().fill_path(
+                &path.path,
+                &tile_paint,
+                path.rule,
+                tile_transform,
+                None,
+            );
+            composite_tile(pixmap, &tile_pixmap, x, y, blend_mode);
+            Some(())
+        },
+    )
+}
+
+/// Renders a stroke that is too large for tiny-skia to rasterize directly;
+/// see [`render_fill_path_tiled`] for the tiling strategy.
+fn render_stroke_path_tiled(
+    path: &StrokePath,
+    paint: &tiny_skia::Paint,
+    transform: tiny_skia::Transform,
+    pixmap: &mut tiny_skia::PixmapMut,
+    bounds: tiny_skia::Rect,
+    max_dim: u32,
+) -> Option<()> {
+    let blend_mode = paint.blend_mode;
+    let mut tile_paint = paint.clone();
+    tile_paint.blend_mode = tiny_skia::BlendMode::SourceOver;
+
+    for_each_tile(
+        pixmap.width(),
+        pixmap.height(),
+        bounds,
+        max_dim,
+        |x, y, tw, th| {
+            let mut tile_pixmap = tiny_skia::Pixmap::new(tw, th)?;
+            let tile_transform = transform.post_translate(-(x as f32), -(y as f32));
+            tile_pixmap.as_mut().stroke_path(
+                &path.path,
+                &tile_paint,
+                &path.stroke,
+                tile_transform,
+                None,
+            );
+            composite_tile(pixmap, &tile_pixmap, x, y, blend_mode);
+            Some(())
+        },
+    )
+}
+
 pub fn render_stroke_path(
     path: &StrokePath,
     blend_mode: tiny_skia::BlendMode,
@@ -185,6 +922,12 @@ pub fn render_stroke_path(
     transform: tiny_skia::Transform,
     pixmap: &mut tiny_skia::PixmapMut,
 ) -> Option<()> {
+    if let Some(ref shadow) = path.shadow {
+        if shadow.color.alpha() > 0.0 {
+            render_stroke_path_shadow(path, shadow, blend_mode, ctx, transform, pixmap);
+        }
+    }
+
     let pattern_pixmap;
     let mut paint = tiny_skia::Paint::default();
     match path.paint {
@@ -208,10 +951,394 @@ pub fn render_stroke_path(
 
     paint.anti_alias = path.anti_alias;
     paint.blend_mode = blend_mode;
+    paint.force_hq_pipeline = ctx.force_hq;
 
-    // TODO: fallback to a stroked path when possible
+    // Measure the stroke's actual painted outline, not the bare centerline:
+    // a thick stroke's rasterized extent can exceed the raster limit even
+    // when its centerline bbox doesn't.
+    let outline = stroke_outline(&path.path, &path.stroke);
+    match oversized_bounds(&outline, transform, ctx.limits.max_raster_dimension) {
+        Some(bounds) => render_stroke_path_tiled(
+            path,
+            &paint,
+            transform,
+            pixmap,
+            bounds,
+            ctx.limits.max_raster_dimension,
+        ),
+        None => {
+            pixmap.stroke_path(&path.path, &paint, &path.stroke, transform, None);
+            Some(())
+        }
+    }
+}
 
-    pixmap.stroke_path(&path.path, &paint, &path.stroke, transform, None);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Some(())
+    fn rect_path(x: f32, y: f32, w: f32, h: f32) -> tiny_skia::Path {
+        let mut pb = tiny_skia::PathBuilder::new();
+        pb.push_rect(tiny_skia::Rect::from_xywh(x, y, w, h).unwrap());
+        pb.finish().unwrap()
+    }
+
+    #[test]
+    fn clamped_box_radius_is_bounded_by_mask_size() {
+        // A tiny mask must not pay for an attacker-controlled `stdDeviation`:
+        // the radius is capped at half the mask's longest side.
+        assert_eq!(clamped_box_radius(1_000_000.0, 10), 5);
+        assert_eq!(clamped_box_radius(0.0, 10), 0);
+        // Below the cap, the usual 3-box-blur-passes approximation holds.
+        assert_eq!(clamped_box_radius(1.0, 1000), 1);
+    }
+
+    #[test]
+    fn stroke_outline_widens_bounds_past_the_centerline() {
+        let path = rect_path(0.0, 0.0, 10.0, 10.0);
+        let stroke = tiny_skia::Stroke {
+            width: 20.0,
+            ..Default::default()
+        };
+
+        let centerline_bounds = path.bounds();
+        let outline_bounds = stroke_outline(&path, &stroke).bounds();
+
+        assert!(outline_bounds.width() > centerline_bounds.width());
+        assert!(outline_bounds.height() > centerline_bounds.height());
+    }
+
+    #[test]
+    fn stroke_outline_falls_back_to_the_centerline_for_zero_width() {
+        let path = rect_path(0.0, 0.0, 10.0, 10.0);
+        let stroke = tiny_skia::Stroke {
+            width: 0.0,
+            ..Default::default()
+        };
+
+        assert_eq!(stroke_outline(&path, &stroke).bounds(), path.bounds());
+    }
+
+    #[test]
+    fn stroke_outline_emits_a_cap_dot_for_a_zero_length_subpath() {
+        // A single-point subpath has no direction to stroke along, but round
+        // (or square) caps still paint a dot there -- the outliner must not
+        // just drop it.
+        let mut pb = tiny_skia::PathBuilder::new();
+        pb.move_to(5.0, 5.0);
+        pb.close();
+        let path = pb.finish().unwrap();
+
+        let stroke = tiny_skia::Stroke {
+            width: 4.0,
+            line_cap: tiny_skia::LineCap::Round,
+            ..Default::default()
+        };
+
+        let bounds = stroke_outline(&path, &stroke).bounds();
+        assert!(bounds.width() > 0.0);
+        assert!(bounds.height() > 0.0);
+    }
+
+    #[test]
+    fn stroke_outline_dashes_before_outlining() {
+        // A long horizontal line dashed 4-on/4-off should outline into
+        // several short, disjoint quads -- tiny-skia's `.stroke()` ignores
+        // the dash array, so `stroke_outline` must dash the path first.
+        let mut pb = tiny_skia::PathBuilder::new();
+        pb.move_to(0.0, 0.0);
+        pb.line_to(40.0, 0.0);
+        let path = pb.finish().unwrap();
+
+        let stroke = tiny_skia::Stroke {
+            width: 2.0,
+            dash: tiny_skia::StrokeDash::new(vec![4.0, 4.0], 0.0),
+            ..Default::default()
+        };
+
+        let outline = stroke_outline(&path, &stroke);
+        let bounds = outline.bounds();
+
+        // One closed contour per dash, not a single undashed quad...
+        let contours = outline
+            .segments()
+            .filter(|s| matches!(s, tiny_skia::PathSegment::MoveTo(_)))
+            .count();
+        assert!(contours > 1);
+        // ...while the dashed outline still spans the full original length...
+        assert!(bounds.width() > 30.0);
+        // ...and stays within the stroke's width.
+        assert!(bounds.height() <= 2.0 + 0.01);
+    }
+
+    #[test]
+    fn transform_scale_is_one_for_identity() {
+        assert_eq!(transform_scale(tiny_skia::Transform::identity()), 1.0);
+    }
+
+    #[test]
+    fn transform_scale_follows_uniform_scaling() {
+        let transform = tiny_skia::Transform::from_scale(2.0, 2.0);
+        assert_eq!(transform_scale(transform), 2.0);
+    }
+
+    #[test]
+    fn transform_vector_ignores_translation() {
+        let transform = tiny_skia::Transform::from_translate(100.0, 50.0);
+        assert_eq!(transform_vector(transform, (3.0, -2.0)), (3.0, -2.0));
+    }
+
+    #[test]
+    fn transform_vector_rotates_the_offset() {
+        // A 90-degree rotation should swap the axes (with a sign flip), not
+        // just rescale the offset's magnitude in place.
+        let transform = tiny_skia::Transform::from_rotate(90.0);
+        let (x, y) = transform_vector(transform, (1.0, 0.0));
+        assert!((x - 0.0).abs() < 0.001);
+        assert!((y - 1.0).abs() < 0.001);
+    }
+
+    fn drop_shadow_primitive(dx: f32, dy: f32, std_dev: f32) -> usvg::filter::Primitive {
+        usvg::filter::Primitive {
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            color_interpolation: usvg::filter::ColorInterpolation::SRGB,
+            result: String::new(),
+            kind: usvg::filter::Kind::DropShadow(usvg::filter::DropShadow {
+                dx,
+                dy,
+                std_dev_x: usvg::filter::PositiveF32::new(std_dev).unwrap(),
+                std_dev_y: usvg::filter::PositiveF32::new(std_dev).unwrap(),
+                color: usvg::Color::black(),
+                opacity: usvg::Opacity::ONE,
+            }),
+        }
+    }
+
+    fn flood_primitive() -> usvg::filter::Primitive {
+        usvg::filter::Primitive {
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            color_interpolation: usvg::filter::ColorInterpolation::SRGB,
+            result: String::new(),
+            kind: usvg::filter::Kind::Flood(usvg::filter::Flood {
+                color: usvg::Color::black(),
+                opacity: usvg::Opacity::ONE,
+            }),
+        }
+    }
+
+    fn filter_with(primitives: Vec<usvg::filter::Primitive>) -> usvg::filter::Filter {
+        usvg::filter::Filter {
+            rect: tiny_skia::NonZeroRect::from_xywh(0.0, 0.0, 100.0, 100.0).unwrap(),
+            primitives,
+        }
+    }
+
+    #[test]
+    fn shadow_target_picks_fill_when_fill_paints_first() {
+        assert_eq!(
+            shadow_target(usvg::PaintOrder::FillAndStroke, true, true),
+            ShadowTarget::Fill
+        );
+    }
+
+    #[test]
+    fn shadow_target_picks_stroke_when_stroke_paints_first() {
+        assert_eq!(
+            shadow_target(usvg::PaintOrder::StrokeAndFill, true, true),
+            ShadowTarget::Stroke
+        );
+    }
+
+    #[test]
+    fn shadow_target_falls_back_to_whichever_path_exists() {
+        // Only a stroke: even though fill paints first, there's no fill path
+        // to carry the shadow, so it falls back to the stroke.
+        assert_eq!(
+            shadow_target(usvg::PaintOrder::FillAndStroke, false, true),
+            ShadowTarget::Stroke
+        );
+        // Only a fill: even though stroke paints first, there's no stroke
+        // path to carry the shadow, so it falls back to the fill.
+        assert_eq!(
+            shadow_target(usvg::PaintOrder::StrokeAndFill, true, false),
+            ShadowTarget::Fill
+        );
+    }
+
+    #[test]
+    fn shadow_target_is_none_without_fill_or_stroke() {
+        assert_eq!(
+            shadow_target(usvg::PaintOrder::FillAndStroke, false, false),
+            ShadowTarget::None
+        );
+    }
+
+    fn test_shadow() -> PathShadow {
+        PathShadow {
+            offset: (1.0, 1.0),
+            blur: 2.0,
+            color: tiny_skia::Color::BLACK,
+            companion: None,
+        }
+    }
+
+    #[test]
+    fn shadow_for_paths_attaches_the_other_paint_as_a_companion() {
+        let fill = (Rc::new(rect_path(0.0, 0.0, 10.0, 10.0)), tiny_skia::FillRule::Winding);
+        let stroke = (
+            Rc::new(rect_path(0.0, 0.0, 10.0, 10.0)),
+            tiny_skia::Stroke {
+                width: 20.0,
+                ..Default::default()
+            },
+        );
+
+        // Default paint order: fill first, so it carries the shadow and a
+        // `Stroke` companion for the stroke that paints after it.
+        let (fill_shadow, stroke_shadow) = shadow_for_paths(
+            test_shadow(),
+            usvg::PaintOrder::FillAndStroke,
+            Some(fill.clone()),
+            Some(stroke.clone()),
+        );
+        assert!(stroke_shadow.is_none());
+        assert!(matches!(
+            fill_shadow.unwrap().companion,
+            Some(ShadowCompanion::Stroke { .. })
+        ));
+
+        // Reversed paint order: the stroke carries the shadow and a `Fill`
+        // companion instead.
+        let (fill_shadow, stroke_shadow) =
+            shadow_for_paths(test_shadow(), usvg::PaintOrder::StrokeAndFill, Some(fill), Some(stroke));
+        assert!(fill_shadow.is_none());
+        assert!(matches!(
+            stroke_shadow.unwrap().companion,
+            Some(ShadowCompanion::Fill { .. })
+        ));
+    }
+
+    #[test]
+    fn shadow_for_paths_has_no_companion_with_only_one_paint() {
+        let fill = (Rc::new(rect_path(0.0, 0.0, 10.0, 10.0)), tiny_skia::FillRule::Winding);
+
+        let (fill_shadow, stroke_shadow) = shadow_for_paths(
+            test_shadow(),
+            usvg::PaintOrder::FillAndStroke,
+            Some(fill),
+            None,
+        );
+        assert!(stroke_shadow.is_none());
+        assert!(fill_shadow.unwrap().companion.is_none());
+    }
+
+    #[test]
+    fn union_rect_covers_both_inputs() {
+        let a = tiny_skia::Rect::from_ltrb(0.0, 0.0, 10.0, 10.0).unwrap();
+        let b = tiny_skia::Rect::from_ltrb(5.0, -5.0, 20.0, 8.0).unwrap();
+
+        let union = union_rect(a, b);
+        assert_eq!(union.left(), 0.0);
+        assert_eq!(union.top(), -5.0);
+        assert_eq!(union.right(), 20.0);
+        assert_eq!(union.bottom(), 10.0);
+    }
+
+    #[test]
+    fn single_drop_shadow_is_none_for_no_filters() {
+        assert!(single_drop_shadow(&[]).is_none());
+    }
+
+    #[test]
+    fn single_drop_shadow_is_none_for_more_than_one_primitive() {
+        let filter = filter_with(vec![
+            drop_shadow_primitive(1.0, 1.0, 2.0),
+            drop_shadow_primitive(1.0, 1.0, 2.0),
+        ]);
+        assert!(single_drop_shadow(std::slice::from_ref(&filter)).is_none());
+    }
+
+    #[test]
+    fn single_drop_shadow_is_none_for_more_than_one_filter() {
+        let filters = vec![
+            filter_with(vec![drop_shadow_primitive(1.0, 1.0, 2.0)]),
+            filter_with(vec![drop_shadow_primitive(1.0, 1.0, 2.0)]),
+        ];
+        assert!(single_drop_shadow(&filters).is_none());
+    }
+
+    #[test]
+    fn single_drop_shadow_is_none_for_a_non_drop_shadow_primitive() {
+        let filter = filter_with(vec![flood_primitive()]);
+        assert!(single_drop_shadow(std::slice::from_ref(&filter)).is_none());
+    }
+
+    #[test]
+    fn single_drop_shadow_matches_a_lone_drop_shadow_primitive() {
+        let filter = filter_with(vec![drop_shadow_primitive(3.0, -2.0, 4.0)]);
+        let shadow = single_drop_shadow(std::slice::from_ref(&filter)).unwrap();
+        assert_eq!(shadow.offset, (3.0, -2.0));
+        assert_eq!(shadow.blur, 4.0);
+    }
+
+    #[test]
+    fn oversized_bounds_is_none_under_the_limit() {
+        let path = rect_path(0.0, 0.0, 100.0, 100.0);
+        assert!(oversized_bounds(&path, tiny_skia::Transform::identity(), 8191).is_none());
+    }
+
+    #[test]
+    fn oversized_bounds_fires_past_the_limit() {
+        let path = rect_path(0.0, 0.0, 20_000.0, 100.0);
+        let bounds = oversized_bounds(&path, tiny_skia::Transform::identity(), 8191).unwrap();
+        assert_eq!(bounds.width(), 20_000.0);
+    }
+
+    #[test]
+    fn for_each_tile_covers_the_bounds_exactly_once() {
+        let bounds = tiny_skia::Rect::from_ltrb(0.0, 0.0, 20.0, 10.0).unwrap();
+        let mut tiles = Vec::new();
+        for_each_tile(20, 10, bounds, 8, |x, y, w, h| {
+            tiles.push((x, y, w, h));
+            Some(())
+        });
+
+        // 20x10 tiled at max 8 -> 3 columns (8, 8, 4) x 2 rows (8, 2).
+        assert_eq!(tiles.len(), 6);
+        for (x, y, w, h) in &tiles {
+            assert!(*w <= 8 && *h <= 8);
+            assert!(x + w <= 20 && y + h <= 10);
+        }
+        let covered: u32 = tiles.iter().map(|(_, _, w, h)| w * h).sum();
+        assert_eq!(covered, 20 * 10);
+    }
+
+    #[test]
+    fn for_each_tile_is_none_when_bounds_are_off_canvas() {
+        let bounds = tiny_skia::Rect::from_ltrb(50.0, 50.0, 60.0, 60.0).unwrap();
+        assert!(for_each_tile(20, 10, bounds, 8, |_, _, _, _| Some(())).is_none());
+    }
+
+    #[test]
+    fn shadow_bounds_pads_by_three_sigma_and_offset() {
+        let bounds = tiny_skia::Rect::from_ltrb(0.0, 0.0, 10.0, 10.0).unwrap();
+        let shadow = PathShadow {
+            offset: (2.0, -3.0),
+            blur: 4.0,
+            color: tiny_skia::Color::BLACK,
+            companion: None,
+        };
+
+        let padded = shadow_bounds(bounds, &shadow).unwrap();
+        assert_eq!(padded.left(), 0.0 + 2.0f32.min(0.0) - 12.0);
+        assert_eq!(padded.top(), 0.0 + (-3.0f32).min(0.0) - 12.0);
+        assert_eq!(padded.right(), 10.0 + 2.0f32.max(0.0) + 12.0);
+        assert_eq!(padded.bottom(), 10.0 + (-3.0f32).max(0.0) + 12.0);
+    }
 }